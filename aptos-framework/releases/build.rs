@@ -0,0 +1,143 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Walks `artifacts/<release>/` at compile time and serializes every `.mv` module blob (and the
+//! release's `.errmap` error-description file, if any) it finds into a generated Rust source file
+//! so the release tree can be embedded directly into the binary. This avoids the `ReleaseFetcher`
+//! filesystem lookup for crates that get linked into tools with no artifacts directory alongside
+//! them (e.g. standalone/Docker deployments).
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+const ARTIFACTS_DIR: &str = "artifacts";
+const MODULE_EXTENSION: &str = "mv";
+const ERROR_DESC_EXTENSION: &str = "errmap";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", ARTIFACTS_DIR);
+
+    let artifacts_dir = Path::new(ARTIFACTS_DIR);
+    let releases = if artifacts_dir.is_dir() {
+        let releases = collect_releases(artifacts_dir);
+        let current = releases
+            .iter()
+            .find(|release| release.name == "current")
+            .unwrap_or_else(|| {
+                panic!(
+                    "artifacts/current is missing: the `current` release must always be \
+                     embeddable so CURRENT_MODULE_BLOBS can't panic at runtime in a well-formed \
+                     build"
+                )
+            });
+        assert!(
+            current.error_map.is_some(),
+            "artifacts/current has no .errmap file: CURRENT_ERROR_DESCRIPTIONS can't be \
+             embedded, so it would panic at runtime in a well-formed build"
+        );
+        releases
+    } else {
+        Vec::new()
+    };
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR not set");
+    let dest_path = Path::new(&out_dir).join("embedded_releases.rs");
+    fs::write(dest_path, render(&releases)).expect("failed to write embedded_releases.rs");
+}
+
+/// One embedded release: its name, the `.mv` module files it contains (in the same order
+/// they'd be discovered by `find_filenames` so genesis publishing order is unaffected), and its
+/// `.errmap` file, if it has one.
+struct EmbeddedRelease {
+    name: String,
+    modules: Vec<(String, PathBuf)>,
+    error_map: Option<PathBuf>,
+}
+
+fn collect_releases(artifacts_dir: &Path) -> Vec<EmbeddedRelease> {
+    let mut releases = Vec::new();
+    let mut entries: Vec<_> = fs::read_dir(artifacts_dir)
+        .expect("failed to read artifacts directory")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let mut modules = Vec::new();
+        let mut error_maps = Vec::new();
+        walk(&entry.path(), &mut modules, &mut error_maps);
+        modules.sort_by(|(a, _), (b, _)| a.cmp(b));
+        error_maps.sort();
+        releases.push(EmbeddedRelease {
+            name,
+            modules,
+            error_map: error_maps.into_iter().next(),
+        });
+    }
+    releases
+}
+
+fn walk(dir: &Path, modules: &mut Vec<(String, PathBuf)>, error_maps: &mut Vec<PathBuf>) {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|_| panic!("failed to read {}", dir.display()))
+        .filter_map(|entry| entry.ok())
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            walk(&path, modules, error_maps);
+        } else {
+            match path.extension().and_then(|ext| ext.to_str()) {
+                Some(MODULE_EXTENSION) => {
+                    let module_path = path.to_string_lossy().into_owned();
+                    modules.push((module_path, path));
+                }
+                Some(ERROR_DESC_EXTENSION) => error_maps.push(path),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn render(releases: &[EmbeddedRelease]) -> String {
+    let mut out = String::new();
+    out.push_str("// @generated by build.rs -- do not edit.\n");
+
+    out.push_str("pub static EMBEDDED_RELEASES: &[(&str, &[(&str, &[u8])])] = &[\n");
+    for release in releases {
+        out.push_str(&format!("    ({:?}, &[\n", release.name));
+        for (module_path, file_path) in &release.modules {
+            out.push_str(&format!(
+                "        ({:?}, include_bytes!({:?})),\n",
+                module_path,
+                canonicalize(file_path)
+            ));
+        }
+        out.push_str("    ]),\n");
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("pub static EMBEDDED_ERROR_MAPS: &[(&str, &[u8])] = &[\n");
+    for release in releases {
+        if let Some(error_map) = &release.error_map {
+            out.push_str(&format!(
+                "    ({:?}, include_bytes!({:?})),\n",
+                release.name,
+                canonicalize(error_map)
+            ));
+        }
+    }
+    out.push_str("];\n");
+
+    out
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
@@ -0,0 +1,204 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Transaction-builder ABIs extracted from the compiled modules of a release.
+//!
+//! This is the missing input an `aptos-sdk-builder`-style code generator needs to emit typed
+//! transaction constructors in Rust/TypeScript/Python, without every generator having to
+//! re-deserialize the module blobs itself.
+//!
+//! Note: a compiled `.mv` module carries no source-level doc comments, so [`ScriptABI`]
+//! intentionally has no `doc()` accessor. A generator that wants human-readable docs has to get
+//! them from a separate docgen pass (e.g. the `.md` output the Move compiler can emit alongside
+//! the bytecode) and join them onto these ABIs by `(module_id, name)`.
+
+use crate::{current_modules, load_modules_from_release};
+use anyhow::Result;
+use move_binary_format::{
+    access::ModuleAccess,
+    file_format::Visibility,
+    normalized::{Module as NormalizedModule, Type},
+    CompiledModule,
+};
+use move_core_types::language_storage::ModuleId;
+
+/// The ABI of a single public entry function, sufficient for a code generator to emit a typed
+/// transaction-builder wrapper around it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptABI {
+    name: String,
+    module_id: ModuleId,
+    ty_args: Vec<TypeArgumentABI>,
+    args: Vec<ArgumentABI>,
+}
+
+impl ScriptABI {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn module_id(&self) -> &ModuleId {
+        &self.module_id
+    }
+
+    pub fn ty_args(&self) -> &[TypeArgumentABI] {
+        &self.ty_args
+    }
+
+    pub fn args(&self) -> &[ArgumentABI] {
+        &self.args
+    }
+}
+
+/// A generic type parameter declared on an entry function, e.g. the `CoinType` in
+/// `transfer<CoinType>(...)`. Compiled modules don't keep source-level type parameter names, so
+/// [`Self::name`] is positional: `T0`, `T1`, ...
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeArgumentABI {
+    name: String,
+}
+
+impl TypeArgumentABI {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A single typed argument to an entry function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentABI {
+    name: String,
+    type_: Type,
+}
+
+impl ArgumentABI {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+}
+
+/// Walk [`current_modules`] and emit a [`ScriptABI`] for every public entry function, sorted by
+/// module id then function name so generated SDK code is stable across runs.
+pub fn abis() -> Vec<ScriptABI> {
+    extract_abis(current_modules())
+}
+
+/// Same as [`abis`] but targets a specific pinned release instead of the embedded `current` one.
+pub fn abis_for_release(release_name: &str) -> Result<Vec<ScriptABI>> {
+    let blobs = load_modules_from_release(release_name)?;
+    let modules = blobs
+        .iter()
+        .map(|blob| CompiledModule::deserialize(blob))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(extract_abis(&modules))
+}
+
+fn extract_abis(modules: &[CompiledModule]) -> Vec<ScriptABI> {
+    let mut abis: Vec<ScriptABI> = modules
+        .iter()
+        .flat_map(|module| {
+            let module_id = module.self_id();
+            let normalized = NormalizedModule::new(module);
+            normalized
+                .functions
+                .into_iter()
+                .filter(|(_, func)| func.visibility == Visibility::Public && func.is_entry)
+                .map(move |(name, func)| ScriptABI {
+                    name: name.into_string(),
+                    module_id: module_id.clone(),
+                    ty_args: func
+                        .type_parameters
+                        .iter()
+                        .enumerate()
+                        .map(|(i, _)| TypeArgumentABI {
+                            name: format!("T{}", i),
+                        })
+                        .collect(),
+                    args: func
+                        .parameters
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, type_)| ArgumentABI {
+                            name: format!("arg{}", i),
+                            type_,
+                        })
+                        .collect(),
+                })
+        })
+        .collect();
+
+    abis.sort_by(|a, b| (a.module_id.clone(), a.name.clone()).cmp(&(b.module_id.clone(), b.name.clone())));
+    abis
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use move_binary_format::file_format::{
+        empty_module, FunctionDefinition, FunctionHandle, FunctionHandleIndex, IdentifierIndex,
+        ModuleHandleIndex, Signature, SignatureIndex, SignatureToken,
+    };
+    use move_core_types::identifier::Identifier;
+
+    fn push_function(
+        module: &mut CompiledModule,
+        name: &str,
+        visibility: Visibility,
+        is_entry: bool,
+        parameters: Vec<SignatureToken>,
+    ) {
+        let name_idx = IdentifierIndex(module.identifiers.len() as u16);
+        module.identifiers.push(Identifier::new(name).unwrap());
+
+        let parameters_idx = SignatureIndex(module.signatures.len() as u16);
+        module.signatures.push(Signature(parameters));
+
+        let return_idx = SignatureIndex(module.signatures.len() as u16);
+        module.signatures.push(Signature(vec![]));
+
+        let handle_idx = FunctionHandleIndex(module.function_handles.len() as u16);
+        module.function_handles.push(FunctionHandle {
+            module: ModuleHandleIndex(0),
+            name: name_idx,
+            parameters: parameters_idx,
+            return_: return_idx,
+            type_parameters: vec![],
+        });
+
+        module.function_defs.push(FunctionDefinition {
+            function: handle_idx,
+            visibility,
+            is_entry,
+            acquires_global_resources: vec![],
+            code: None,
+        });
+    }
+
+    #[test]
+    fn extract_abis_keeps_only_public_entry_functions_and_sorts_them() {
+        let mut module = empty_module();
+        // Declared out of alphabetical order, and mixed with functions that must be filtered
+        // out, to exercise both the `Visibility::Public && is_entry` filter and the sort.
+        push_function(&mut module, "withdraw", Visibility::Public, true, vec![SignatureToken::U64]);
+        push_function(&mut module, "private_helper", Visibility::Private, false, vec![]);
+        push_function(&mut module, "public_not_entry", Visibility::Public, false, vec![]);
+        push_function(
+            &mut module,
+            "deposit",
+            Visibility::Public,
+            true,
+            vec![SignatureToken::Address, SignatureToken::U64],
+        );
+
+        let abis = extract_abis(&[module]);
+
+        let names: Vec<&str> = abis.iter().map(ScriptABI::name).collect();
+        assert_eq!(names, vec!["deposit", "withdraw"]);
+        assert_eq!(abis[0].args().len(), 2);
+        assert_eq!(abis[1].args().len(), 1);
+    }
+}
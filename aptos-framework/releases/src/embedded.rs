@@ -0,0 +1,32 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! The `artifacts/` tree embedded into the binary at build time by `build.rs`, keyed by release
+//! name. Used as a fallback by [`crate::load_modules_from_release`] and
+//! [`crate::load_error_descriptions_from_release`] when no filesystem release is available.
+
+include!(concat!(env!("OUT_DIR"), "/embedded_releases.rs"));
+
+/// Module blobs for `release_name`, if it was embedded at build time.
+pub fn module_blobs(release_name: &str) -> Option<Vec<Vec<u8>>> {
+    EMBEDDED_RELEASES
+        .iter()
+        .find(|(name, _)| *name == release_name)
+        .map(|(_, modules)| modules.iter().map(|(_, bytes)| bytes.to_vec()).collect())
+}
+
+/// The raw `.errmap` bytes for `release_name`, if it was embedded at build time.
+pub fn error_map_bytes(release_name: &str) -> Option<&'static [u8]> {
+    EMBEDDED_ERROR_MAPS
+        .iter()
+        .find(|(name, _)| *name == release_name)
+        .map(|(_, bytes)| *bytes)
+}
+
+/// The names of every release embedded into the binary at build time.
+pub fn release_names() -> Vec<String> {
+    EMBEDDED_RELEASES
+        .iter()
+        .map(|(name, _)| (*name).to_owned())
+        .collect()
+}
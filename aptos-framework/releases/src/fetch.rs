@@ -0,0 +1,235 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Remote release fetching with on-disk caching, for tooling that wants to target a framework
+//! release that isn't compiled into the binary (e.g. pinning an older on-chain framework for
+//! replay) without disturbing the existing `current` fast path.
+//!
+//! A fetched release is cached as a single `bcs`-encoded [`CachedRelease`], keyed by release
+//! name, alongside the content hash it was fetched with so a later [`fetch_release`] call can
+//! tell whether the cache is still fresh.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Overrides the cache location when set, so tests (and operators who don't want the platform
+/// default) don't have to share the real `dirs::cache_dir()`.
+const CACHE_DIR_ENV_VAR: &str = "APTOS_FRAMEWORK_RELEASE_CACHE_DIR";
+
+/// A pinned remote release: where to fetch it from and the content hash it's expected to match.
+pub struct RemoteRelease {
+    pub url: String,
+    pub expected_hash: String,
+}
+
+/// Everything [`fetch_release`] downloads and caches for one release: its module blobs, and its
+/// error-description map if the remote bundle carries one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedRelease {
+    module_blobs: Vec<Vec<u8>>,
+    error_map_bytes: Option<Vec<u8>>,
+}
+
+fn cache_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_ENV_VAR) {
+        return PathBuf::from(dir);
+    }
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("aptos-framework-releases")
+}
+
+fn bundle_path(release_name: &str) -> PathBuf {
+    cache_dir().join(release_name).join("release.bcs")
+}
+
+fn hash_path(release_name: &str) -> PathBuf {
+    cache_dir().join(release_name).join("HASH")
+}
+
+/// Fetch the release bundle from `remote`, verify it against the expected content hash, and atomically
+/// install it into the local cache under `release_name`. Reuses the cached copy instead of
+/// re-downloading when the cached hash already matches the one requested.
+pub fn fetch_release(release_name: &str, remote: &RemoteRelease) -> Result<PathBuf> {
+    if is_cached(release_name, &remote.expected_hash) {
+        return Ok(bundle_path(release_name));
+    }
+
+    let bytes = reqwest::blocking::get(&remote.url)
+        .with_context(|| format!("failed to fetch release bundle from {}", remote.url))?
+        .bytes()
+        .with_context(|| format!("failed to read release bundle from {}", remote.url))?;
+
+    install_bundle(release_name, &bytes, &remote.expected_hash)
+}
+
+/// Verify `bytes` (a `bcs`-encoded [`CachedRelease`]) against `expected_hash` and atomically
+/// install them into the cache under `release_name`. Split out from [`fetch_release`] so the
+/// hashing/install logic can be exercised without a network round-trip.
+fn install_bundle(release_name: &str, bytes: &[u8], expected_hash: &str) -> Result<PathBuf> {
+    let actual_hash = content_hash(bytes);
+    if actual_hash != expected_hash {
+        bail!(
+            "content hash mismatch for release `{}`: expected {}, got {}",
+            release_name,
+            expected_hash,
+            actual_hash
+        );
+    }
+
+    let dest_dir = cache_dir().join(release_name);
+    fs::create_dir_all(&dest_dir)
+        .with_context(|| format!("failed to create cache dir {}", dest_dir.display()))?;
+
+    // Write to a uniquely-named temp file in the same directory and rename into place, so two
+    // concurrent fetches of the same release (or a reader racing an install) never observe a
+    // partially-written or mixed bundle. The pid + per-process counter keeps the name unique
+    // both across processes and across concurrent calls within this one.
+    let tmp_path = dest_dir.join(format!(
+        "release.bcs.{}.{}.tmp",
+        std::process::id(),
+        next_tmp_suffix()
+    ));
+    {
+        let mut tmp_file = fs::File::create(&tmp_path)
+            .with_context(|| format!("failed to create {}", tmp_path.display()))?;
+        tmp_file.write_all(bytes)?;
+    }
+    let bundle = bundle_path(release_name);
+    fs::rename(&tmp_path, &bundle)
+        .with_context(|| format!("failed to install release bundle to {}", bundle.display()))?;
+    fs::write(hash_path(release_name), &actual_hash)
+        .with_context(|| format!("failed to record hash for release `{}`", release_name))?;
+
+    Ok(bundle)
+}
+
+static TMP_SUFFIX: AtomicU64 = AtomicU64::new(0);
+
+fn next_tmp_suffix() -> u64 {
+    TMP_SUFFIX.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Whether `release_name` is already materialized in the local cache with the expected hash.
+fn is_cached(release_name: &str, expected_hash: &str) -> bool {
+    let cached_hash = fs::read_to_string(hash_path(release_name)).ok();
+    bundle_path(release_name).is_file() && cached_hash.as_deref() == Some(expected_hash)
+}
+
+fn content_hash(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn cached_release(release_name: &str) -> Option<CachedRelease> {
+    let bytes = fs::read(bundle_path(release_name)).ok()?;
+    bcs::from_bytes(&bytes).ok()
+}
+
+/// The module blobs for `release_name`, if it's already materialized in the local cache.
+pub fn cached_release_blobs(release_name: &str) -> Option<Vec<Vec<u8>>> {
+    cached_release(release_name).map(|release| release.module_blobs)
+}
+
+/// The raw `.errmap` bytes for `release_name`, if it's already materialized in the local cache
+/// and the cached bundle carried one.
+pub fn cached_error_map_bytes(release_name: &str) -> Option<Vec<u8>> {
+    cached_release(release_name)?.error_map_bytes
+}
+
+/// The release names already materialized in the local cache.
+pub fn cached_releases() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(cache_dir()) else {
+        return Vec::new();
+    };
+    let mut releases: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| bundle_path_exists(&entry.path()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    releases.sort();
+    releases
+}
+
+fn bundle_path_exists(release_dir: &Path) -> bool {
+    release_dir.join("release.bcs").is_file()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `cache_dir()` reads a process-wide env var, so tests that touch it must not run
+    // concurrently with each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn with_temp_cache_dir(f: impl FnOnce()) {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        std::env::set_var(CACHE_DIR_ENV_VAR, dir.path());
+        f();
+        std::env::remove_var(CACHE_DIR_ENV_VAR);
+    }
+
+    #[test]
+    fn install_bundle_verifies_hash_and_installs_atomically() {
+        with_temp_cache_dir(|| {
+            let release = CachedRelease {
+                module_blobs: vec![vec![1u8, 2, 3]],
+                error_map_bytes: Some(vec![4u8, 5]),
+            };
+            let bytes = bcs::to_bytes(&release).unwrap();
+            let hash = content_hash(&bytes);
+
+            let bundle = install_bundle("demo", &bytes, &hash).unwrap();
+
+            assert!(bundle.is_file());
+            assert!(is_cached("demo", &hash));
+            assert_eq!(cached_release_blobs("demo"), Some(vec![vec![1, 2, 3]]));
+            assert_eq!(cached_error_map_bytes("demo"), Some(vec![4, 5]));
+            assert_eq!(cached_releases(), vec!["demo".to_string()]);
+        });
+    }
+
+    #[test]
+    fn install_bundle_rejects_hash_mismatch_and_leaves_nothing_cached() {
+        with_temp_cache_dir(|| {
+            let release = CachedRelease {
+                module_blobs: vec![vec![9u8]],
+                error_map_bytes: None,
+            };
+            let bytes = bcs::to_bytes(&release).unwrap();
+
+            let err = install_bundle("demo", &bytes, "not-the-real-hash").unwrap_err();
+
+            assert!(err.to_string().contains("hash mismatch"));
+            assert!(!is_cached("demo", "not-the-real-hash"));
+            assert_eq!(cached_release_blobs("demo"), None);
+            assert_eq!(cached_error_map_bytes("demo"), None);
+        });
+    }
+
+    #[test]
+    fn is_cached_invalidates_when_pinned_hash_changes() {
+        with_temp_cache_dir(|| {
+            let release = CachedRelease {
+                module_blobs: vec![vec![7u8]],
+                error_map_bytes: None,
+            };
+            let bytes = bcs::to_bytes(&release).unwrap();
+            let hash = content_hash(&bytes);
+            install_bundle("demo", &bytes, &hash).unwrap();
+
+            assert!(is_cached("demo", &hash));
+            assert!(!is_cached("demo", "some-other-pinned-hash"));
+        });
+    }
+}
@@ -4,22 +4,58 @@
 use anyhow::Result;
 use framework_releases::{Release, ReleaseFetcher};
 use move_binary_format::file_format::CompiledModule;
-use move_command_line_common::files::{extension_equals, find_filenames, MOVE_COMPILED_EXTENSION};
+use move_command_line_common::files::{
+    extension_equals, find_filenames, MOVE_COMPILED_EXTENSION, MOVE_ERROR_DESC_EXTENSION,
+};
+use move_core_types::errmap::ErrorMapping;
 use once_cell::sync::Lazy;
 use std::path::PathBuf;
 
+mod abi;
+mod embedded;
+mod fetch;
+mod manifest;
+
+pub use abi::{abis, abis_for_release, ArgumentABI, ScriptABI, TypeArgumentABI};
+pub use fetch::{cached_releases, fetch_release, RemoteRelease};
+pub use manifest::{load_modules_from_manifest, ManifestStanza, ReleaseManifest};
+
 /// Load the serialized modules from the specified release.
+///
+/// Tries the on-disk `ReleaseFetcher` path first so a checked-out artifacts tree always wins;
+/// falls back to the copy embedded into the binary at build time when the release isn't present
+/// on the filesystem (e.g. the crate is linked into a tool shipped without the `artifacts/`
+/// directory alongside it); finally falls back to a release previously materialized by
+/// [`fetch_release`] into the local cache, so a release that's neither checked out nor compiled
+/// in (e.g. an old on-chain framework pinned for replay) can still be loaded.
 pub fn load_modules_from_release(release_name: &str) -> Result<Vec<Vec<u8>>> {
-    ReleaseFetcher::new(Release::Aptos, release_name).module_blobs()
+    match ReleaseFetcher::new(Release::Aptos, release_name).module_blobs() {
+        Ok(blobs) => Ok(blobs),
+        Err(err) => embedded::module_blobs(release_name)
+            .or_else(|| fetch::cached_release_blobs(release_name))
+            .ok_or(err),
+    }
 }
 
-static CURRENT_MODULE_BLOBS: Lazy<Vec<Vec<u8>>> =
-    Lazy::new(|| load_modules_from_release("current").unwrap());
+/// List the releases embedded into the binary at build time.
+pub fn list_all_releases() -> Result<Vec<String>> {
+    Ok(embedded::release_names())
+}
+
+// `build.rs` fails the build if `artifacts/current` is missing, so as long as the crate was
+// built from a tree that has an `artifacts/` directory at all, the embedded fallback inside
+// `load_modules_from_release` is guaranteed to resolve "current" even when no filesystem release
+// is checked out next to the binary. The `.expect()` below should therefore never fire outside
+// of a misconfigured build (e.g. this crate vendored without its `artifacts/` directory at all).
+static CURRENT_MODULE_BLOBS: Lazy<Vec<Vec<u8>>> = Lazy::new(|| {
+    load_modules_from_release("current")
+        .expect("\"current\" release must be embedded or present on disk; see build.rs")
+});
 
 static CURRENT_MODULES: Lazy<Vec<CompiledModule>> = Lazy::new(|| {
     CURRENT_MODULE_BLOBS
         .iter()
-        .map(|blob| CompiledModule::deserialize(blob).unwrap())
+        .map(|blob| CompiledModule::deserialize(blob).expect("corrupt \"current\" module blob"))
         .collect()
 });
 
@@ -41,3 +77,51 @@ pub fn load_modules_from_paths(paths: &[PathBuf]) -> Vec<Vec<u8>> {
     .map(|file_name| std::fs::read(file_name).unwrap())
     .collect::<Vec<_>>()
 }
+
+/// Load the error-description map bundled with the specified release.
+///
+/// This lets callers translate a `(module_id, abort_code)` pair into the human-readable
+/// category/reason string the release was compiled with, so downstream VM/explorer tooling can
+/// surface meaningful abort messages instead of raw u64 codes.
+///
+/// Follows the same fallback chain as [`load_modules_from_release`]: the on-disk
+/// `ReleaseFetcher` path first, then the copy embedded into the binary at build time, then a
+/// release previously materialized by [`fetch_release`] into the local cache.
+pub fn load_error_descriptions_from_release(release_name: &str) -> Result<ErrorMapping> {
+    match ReleaseFetcher::new(Release::Aptos, release_name).error_map() {
+        Ok(error_map) => Ok(error_map),
+        Err(err) => embedded::error_map_bytes(release_name)
+            .map(|bytes| bytes.to_vec())
+            .or_else(|| fetch::cached_error_map_bytes(release_name))
+            .ok_or(err)
+            .and_then(|bytes| Ok(bcs::from_bytes(&bytes)?)),
+    }
+}
+
+// `build.rs` fails the build if `artifacts/current` has no `.errmap` file, so as long as the
+// crate was built from a tree that has an `artifacts/` directory at all, the embedded fallback
+// inside `load_error_descriptions_from_release` is guaranteed to resolve "current" even when no
+// filesystem release is checked out next to the binary. The `.expect()` below should therefore
+// never fire outside of a misconfigured build (e.g. this crate vendored without its `artifacts/`
+// directory at all).
+static CURRENT_ERROR_DESCRIPTIONS: Lazy<ErrorMapping> = Lazy::new(|| {
+    load_error_descriptions_from_release("current")
+        .expect("\"current\" release must be embedded or present on disk; see build.rs")
+});
+
+pub fn current_error_descriptions() -> &'static ErrorMapping {
+    &CURRENT_ERROR_DESCRIPTIONS
+}
+
+/// Load the error-description map from the specified paths, mirroring the way
+/// [`load_modules_from_paths`] globs `.mv` files but matching `.errmap` artifacts instead.
+pub fn load_error_descriptions_from_paths(paths: &[PathBuf]) -> Result<ErrorMapping> {
+    let file_name = find_filenames(paths, |path| {
+        extension_equals(path, MOVE_ERROR_DESC_EXTENSION)
+    })?
+    .into_iter()
+    .next()
+    .ok_or_else(|| anyhow::anyhow!("no {} file found in {:?}", MOVE_ERROR_DESC_EXTENSION, paths))?;
+    let bytes = std::fs::read(file_name)?;
+    Ok(bcs::from_bytes(&bytes)?)
+}
@@ -0,0 +1,248 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! A declarative release manifest: one stanza per module, blank-line separated, with
+//! `Key: Value` lines (`Module:`, `Package:`, `Order:`, ...) and `#` comments. This lets a
+//! release declare exactly which modules it contains and in what order they publish, instead of
+//! relying on the filesystem globbing order [`crate::load_modules_from_paths`] uses.
+
+use anyhow::{Context, Result};
+use std::{
+    collections::BTreeSet,
+    mem,
+    path::{Path, PathBuf},
+};
+
+/// One line of a manifest stanza.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ManifestLine {
+    /// A `# ...` comment, preserved verbatim (including the leading `#`).
+    Comment(String),
+    /// A `Key: Value` line. Unknown keys are preserved as-is so fields this parser doesn't know
+    /// about still round-trip.
+    Entry { key: String, value: String },
+}
+
+/// A single blank-line-separated block declaring one module.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestStanza {
+    lines: Vec<ManifestLine>,
+}
+
+impl ManifestStanza {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.lines.iter().find_map(|line| match line {
+            ManifestLine::Entry { key: k, value } if k == key => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The `Module:` path declared by this stanza, if any.
+    pub fn module(&self) -> Option<&str> {
+        self.get("Module")
+    }
+
+    /// The `Package:` name declared by this stanza, if any.
+    pub fn package(&self) -> Option<&str> {
+        self.get("Package")
+    }
+
+    /// The `Order:` index declared by this stanza, if any.
+    pub fn order(&self) -> Option<u64> {
+        self.get("Order").and_then(|value| value.parse().ok())
+    }
+
+    fn render(self) -> String {
+        self.lines
+            .into_iter()
+            .map(|line| match line {
+                ManifestLine::Comment(text) => text,
+                ManifestLine::Entry { key, value } => format!("{}: {}", key, value),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A parsed release manifest: an ordered list of stanzas, each declaring one module.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReleaseManifest {
+    stanzas: Vec<ManifestStanza>,
+}
+
+impl ReleaseManifest {
+    /// The stanzas of this manifest, in declaration order.
+    pub fn stanzas(&self) -> &[ManifestStanza] {
+        &self.stanzas
+    }
+
+    /// Parse a manifest from its textual representation.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut stanzas = Vec::new();
+        let mut current = ManifestStanza::default();
+
+        for raw_line in contents.lines() {
+            if raw_line.trim().is_empty() {
+                if !current.lines.is_empty() {
+                    stanzas.push(mem::take(&mut current));
+                }
+                continue;
+            }
+            if raw_line.trim_start().starts_with('#') {
+                current
+                    .lines
+                    .push(ManifestLine::Comment(raw_line.to_string()));
+                continue;
+            }
+            let (key, value) = raw_line.split_once(':').with_context(|| {
+                format!("invalid manifest line, expected `Key: Value`: {:?}", raw_line)
+            })?;
+            current.lines.push(ManifestLine::Entry {
+                key: key.trim().to_string(),
+                value: value.trim().to_string(),
+            });
+        }
+        if !current.lines.is_empty() {
+            stanzas.push(current);
+        }
+
+        Ok(Self { stanzas })
+    }
+
+    /// Parse a manifest from a file on disk.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read manifest at {}", path.display()))?;
+        Self::parse(&contents)
+    }
+
+    /// Render this manifest back to its textual representation: each entry as `Key: Value`,
+    /// comments verbatim, stanzas separated by exactly one blank line, and a trailing newline.
+    /// The input to [`Self::parse`] is reproduced byte-for-byte only when it is already in that
+    /// form; other spacing (runs of blank lines, whitespace around `:`, a missing final newline,
+    /// `\r\n` line endings) is not preserved and comes out normalized.
+    pub fn write(self) -> String {
+        let mut out = self
+            .stanzas
+            .into_iter()
+            .map(ManifestStanza::render)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        out.push('\n');
+        out
+    }
+
+    /// Warn about manifest entries whose declared `.mv` file is missing, or whose `Order:` value
+    /// duplicates another entry's. `base_dir` is the directory `Module:` paths are relative to.
+    pub fn check(&self, base_dir: &Path) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let mut seen_orders = BTreeSet::new();
+
+        for stanza in &self.stanzas {
+            if let Some(module) = stanza.module() {
+                if !base_dir.join(module).is_file() {
+                    warnings.push(format!(
+                        "manifest entry `{}` has no corresponding file",
+                        module
+                    ));
+                }
+            }
+            if let Some(order) = stanza.order() {
+                if !seen_orders.insert(order) {
+                    warnings.push(format!("duplicate manifest `Order: {}`", order));
+                }
+            }
+        }
+        warnings
+    }
+}
+
+/// Load the serialized modules declared by a release manifest, in `Order:` sequence.
+///
+/// `Order:` is authoritative, not the stanzas' text position: a manifest's stanzas can be laid
+/// out however is most readable (e.g. grouped by package) without changing the publish order,
+/// which is exactly the fragility ("whatever order the file happens to list things in") this
+/// manifest format exists to remove.
+pub fn load_modules_from_manifest(path: &Path) -> Result<Vec<Vec<u8>>> {
+    let manifest = ReleaseManifest::from_file(path)?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut entries = manifest
+        .stanzas()
+        .iter()
+        .filter_map(|stanza| stanza.module().map(|module| (stanza, module)))
+        .map(|(stanza, module)| {
+            let order = stanza.order().with_context(|| {
+                format!("manifest entry `{}` is missing a required `Order:` field", module)
+            })?;
+            Ok((order, PathBuf::from(module)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    entries.sort_by_key(|(order, _)| *order);
+
+    entries
+        .into_iter()
+        .map(|(_, module)| {
+            let module_path = base_dir.join(&module);
+            std::fs::read(&module_path)
+                .with_context(|| format!("failed to read module {}", module_path.display()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip_preserves_comments_and_unknown_keys() {
+        let original = "\
+# framework release manifest
+Package: MoveStdlib
+Module: move-stdlib/vector.mv
+Order: 0
+
+Package: AptosFramework
+Module: aptos-framework/coin.mv
+Order: 1
+Experimental: true
+";
+        let manifest = ReleaseManifest::parse(original).unwrap();
+        assert_eq!(manifest.write(), original);
+    }
+
+    #[test]
+    fn checker_flags_missing_file_and_duplicate_order() {
+        let manifest = ReleaseManifest::parse(
+            "Package: A\nModule: a.mv\nOrder: 0\n\nPackage: B\nModule: b.mv\nOrder: 0\n",
+        )
+        .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.mv"), []).unwrap();
+
+        let warnings = manifest.check(dir.path());
+        assert!(warnings.iter().any(|w| w.contains("b.mv")));
+        assert!(warnings.iter().any(|w| w.contains("duplicate")));
+    }
+
+    #[test]
+    fn load_modules_from_manifest_follows_order_not_text_position() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.mv"), [1u8]).unwrap();
+        std::fs::write(dir.path().join("b.mv"), [2u8]).unwrap();
+        std::fs::write(dir.path().join("c.mv"), [3u8]).unwrap();
+
+        // Text position is b, a, c but Order: says a, b, c should publish.
+        let manifest_path = dir.path().join("release.manifest");
+        std::fs::write(
+            &manifest_path,
+            "Package: B\nModule: b.mv\nOrder: 1\n\n\
+             Package: A\nModule: a.mv\nOrder: 0\n\n\
+             Package: C\nModule: c.mv\nOrder: 2\n",
+        )
+        .unwrap();
+
+        let blobs = load_modules_from_manifest(&manifest_path).unwrap();
+        assert_eq!(blobs, vec![vec![1u8], vec![2u8], vec![3u8]]);
+    }
+}